@@ -3,11 +3,14 @@ use librespot::core::authentication::Credentials;
 use librespot::core::cache::Cache;
 use librespot::core::config::SessionConfig;
 use librespot::core::session::Session;
+use librespot::core::spotify_id::SpotifyId;
+use librespot::metadata::{Metadata, Track};
 use rspotify::clients::BaseClient;
 use rspotify::model::{
-    AlbumId, ArtistId, Country, FullAlbum, FullArtist, FullPlaylist, FullTrack, IncludeExternal,
-    Market, PlayableItem, PlaylistId, SearchResult, SearchType, SimplifiedAlbum, SimplifiedTrack,
-    TrackId,
+    AlbumId, ArtistId, Country, EpisodeId, FullAlbum, FullArtist, FullEpisode, FullPlaylist,
+    FullShow, FullTrack, IncludeExternal, Market, PlayableItem, PlaylistId, SearchResult,
+    SearchType, ShowId, SimplifiedAlbum, SimplifiedEpisode, SimplifiedPlaylist, SimplifiedShow,
+    SimplifiedTrack, TrackId,
 };
 use rspotify::{ClientCredsSpotify, Credentials as ClientCredentials};
 use std::fmt;
@@ -16,6 +19,9 @@ use url::Url;
 
 use crate::error::SpotifyError;
 
+/// librespot catalogue whose restrictions apply to downloads
+const CATALOGUE: &str = "premium";
+
 pub struct Spotify {
     // librespot session
     pub session: Session,
@@ -28,15 +34,21 @@ impl Spotify {
     pub async fn new(
         username: &str,
         password: &str,
+        token: Option<&str>,
         client_id: &str,
         client_secret: &str,
         market_country_code: Option<Country>,
     ) -> Result<Spotify, SpotifyError> {
         // librespot
         let cache = Cache::new(Some(Path::new("credentials_cache")), None, None, None).unwrap();
+        // Prefer cached credentials, then a pre-obtained OAuth token, falling
+        // back to the legacy username + password flow.
         let credentials = match cache.credentials() {
             Some(creds) => creds,
-            None => Credentials::with_password(username, password),
+            None => match token {
+                Some(token) => Credentials::with_access_token(token),
+                None => Credentials::with_password(username, password),
+            },
         };
 
         let session = Session::new(SessionConfig::default(), Some(cache));
@@ -113,47 +125,116 @@ impl Spotify {
                 let artist = self.spotify.artist(ArtistId::from_id(id).unwrap()).await?;
                 Ok(SpotifyItem::Artist(artist))
             }
+            "episode" => {
+                let episode = self
+                    .spotify
+                    .get_an_episode(EpisodeId::from_id(id).unwrap(), self.market)
+                    .await?;
+                Ok(SpotifyItem::Episode(episode))
+            }
+            "show" => {
+                let show = self
+                    .spotify
+                    .get_a_show(ShowId::from_id(id).unwrap(), self.market)
+                    .await?;
+                Ok(SpotifyItem::Show(show))
+            }
             // Unsupported / Unimplemented
             _ => Ok(SpotifyItem::Other(uri.to_string())),
         }
     }
 
-    /// Get search results for query
+    /// Whether `cc` (a 2-char ISO country code) occurs in a concatenated country list
+    fn countrylist_contains(list: &str, cc: &str) -> bool {
+        list.as_bytes()
+            .chunks(2)
+            .any(|chunk| chunk == cc.as_bytes())
+    }
+
+    /// Check whether a track is playable in `country` using librespot restriction metadata
+    pub async fn is_available(&self, id: &str, country: &str) -> Result<bool, SpotifyError> {
+        let track = Track::get(&self.session, SpotifyId::from_base62(id)?).await?;
+
+        let mut has_forbidden = false;
+        let mut has_allowed = false;
+        let mut forbidden = String::new();
+        let mut allowed = String::new();
+
+        for restriction in track.restrictions.iter() {
+            // Restrictions are per-catalogue; only the relevant catalogue counts
+            if !restriction.catalogue_strs.iter().any(|c| c == CATALOGUE) {
+                continue;
+            }
+            if let Some(countries) = &restriction.countries_forbidden {
+                has_forbidden = true;
+                forbidden.push_str(countries);
+            }
+            if let Some(countries) = &restriction.countries_allowed {
+                has_allowed = true;
+                allowed.push_str(countries);
+            }
+        }
+
+        Ok((has_forbidden || has_allowed)
+            && (!has_forbidden || !Self::countrylist_contains(&forbidden, country))
+            && (!has_allowed || Self::countrylist_contains(&allowed, country)))
+    }
+
+    /// Get track search results for query
     pub async fn search(&self, query: &str) -> Result<Vec<FullTrack>, SpotifyError> {
-        Ok(self
+        match self
+            .search_typed(query, SearchType::Track, Some(50), Some(0))
+            .await?
+        {
+            SearchResults::Tracks(tracks) => Ok(tracks),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Get search results of the given type for query
+    pub async fn search_typed(
+        &self,
+        query: &str,
+        search_type: SearchType,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<SearchResults, SpotifyError> {
+        let result = self
             .spotify
             .search(
                 query,
-                SearchType::Track,
+                search_type,
                 None,
                 Some(IncludeExternal::Audio),
-                Some(50),
-                Some(0),
+                limit,
+                offset,
             )
-            .await
-            .map(|result| match result {
-                SearchResult::Tracks(page) => page.items,
-                _ => Vec::new(),
-            })
-            .unwrap())
+            .await?;
+        Ok(match result {
+            SearchResult::Tracks(page) => SearchResults::Tracks(page.items),
+            SearchResult::Albums(page) => SearchResults::Albums(page.items),
+            SearchResult::Artists(page) => SearchResults::Artists(page.items),
+            SearchResult::Playlists(page) => SearchResults::Playlists(page.items),
+            SearchResult::Shows(page) => SearchResults::Shows(page.items),
+            SearchResult::Episodes(page) => SearchResults::Episodes(page.items),
+        })
     }
 
     /// Get all tracks from playlist
     pub async fn full_playlist(&self, id: &str) -> Result<Vec<FullTrack>, SpotifyError> {
-        Ok(self
+        let mut tracks: Vec<FullTrack> = Vec::new();
+        let stream = self
             .spotify
-            .playlist(PlaylistId::from_id(id).unwrap(), None, self.market)
-            .await
-            .unwrap()
-            .tracks
-            .items
-            .into_iter()
-            .filter_map(|item| item.track)
-            .flat_map(|p_item| match p_item {
-                PlayableItem::Track(track) => Some(track),
-                _ => None,
-            })
-            .collect::<Vec<FullTrack>>())
+            .playlist_items(PlaylistId::from_id(id).unwrap(), None, self.market);
+
+        pin_mut!(stream);
+        while let Some(item) = stream.try_next().await.unwrap() {
+            if let Some(PlayableItem::Track(track)) = item.track {
+                tracks.push(track)
+            }
+        }
+
+        Ok(tracks)
     }
 
     /// Get all tracks from album
@@ -172,6 +253,21 @@ impl Spotify {
         Ok(tracks)
     }
 
+    /// Get all episodes from show
+    pub async fn full_show(&self, id: &str) -> Result<Vec<SimplifiedEpisode>, SpotifyError> {
+        let mut episodes: Vec<SimplifiedEpisode> = Vec::new();
+        let stream = self
+            .spotify
+            .get_shows_episodes(ShowId::from_id(id).unwrap(), self.market);
+
+        pin_mut!(stream);
+        while let Some(item) = stream.try_next().await.unwrap() {
+            episodes.push(item)
+        }
+
+        Ok(episodes)
+    }
+
     /// Get all tracks from artist
     pub async fn full_artist(&self, id: &str) -> Result<Vec<SimplifiedTrack>, SpotifyError> {
         let mut albums: Vec<SimplifiedAlbum> = Vec::new();
@@ -221,6 +317,18 @@ pub enum SpotifyItem {
     Album(FullAlbum),
     Playlist(FullPlaylist),
     Artist(FullArtist),
+    Episode(FullEpisode),
+    Show(FullShow),
     /// Unimplemented
     Other(String),
 }
+
+#[derive(Debug, Clone)]
+pub enum SearchResults {
+    Tracks(Vec<FullTrack>),
+    Albums(Vec<SimplifiedAlbum>),
+    Artists(Vec<FullArtist>),
+    Playlists(Vec<SimplifiedPlaylist>),
+    Shows(Vec<SimplifiedShow>),
+    Episodes(Vec<SimplifiedEpisode>),
+}
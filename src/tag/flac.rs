@@ -0,0 +1,83 @@
+use metaflac::block::PictureType;
+use metaflac::Tag;
+use std::path::{Path, PathBuf};
+
+use crate::error::SpotifyError;
+
+use super::Field;
+
+pub struct FlacTag {
+    path: PathBuf,
+    tag: Tag,
+    separator: String,
+}
+
+impl FlacTag {
+    /// Load from path
+    pub fn open(path: impl AsRef<Path>) -> Result<FlacTag, SpotifyError> {
+        let tag = Tag::read_from_path(&path).unwrap_or_default();
+
+        Ok(FlacTag {
+            path: path.as_ref().to_owned(),
+            tag,
+            separator: String::new(),
+        })
+    }
+
+    /// Vorbis comment key for a field
+    fn field_key(field: &Field) -> &'static str {
+        match field {
+            Field::Title => "TITLE",
+            Field::Artist => "ARTIST",
+            Field::Album => "ALBUM",
+            Field::TrackNumber => "TRACKNUMBER",
+            Field::DiscNumber => "DISCNUMBER",
+            Field::AlbumArtist => "ALBUMARTIST",
+            Field::Genre => "GENRE",
+            Field::Label => "LABEL",
+            Field::Isrc => "ISRC",
+            Field::Copyright => "COPYRIGHT",
+            Field::Composer => "COMPOSER",
+            Field::Bpm => "BPM",
+            Field::Comment => "COMMENT",
+            Field::Lyrics => "LYRICS",
+        }
+    }
+}
+
+impl super::Tag for FlacTag {
+    fn set_separator(&mut self, separator: &str) {
+        self.separator = separator.to_string();
+    }
+
+    fn set_raw(&mut self, tag: &str, value: Vec<String>) {
+        self.tag
+            .vorbis_comments_mut()
+            .set(tag, vec![value.join(&self.separator)]);
+    }
+
+    fn set_field(&mut self, field: Field, value: Vec<String>) {
+        self.set_raw(Self::field_key(&field), value);
+    }
+
+    fn save(&mut self) -> Result<(), SpotifyError> {
+        Ok(self.tag.write_to_path(&self.path)?)
+    }
+
+    fn add_cover(&mut self, mime: &str, data: Vec<u8>) {
+        self.tag
+            .add_picture(mime.to_owned(), PictureType::CoverFront, data);
+    }
+
+    fn set_release_date(&mut self, date: String) {
+        self.set_raw("DATE", vec![date]);
+    }
+
+    fn add_unique_file_identifier(&mut self, track_id: &str) {
+        self.set_raw("SPOTIFY_TRACK_ID", vec![track_id.to_string()]);
+    }
+
+    fn set_lyrics(&mut self, lyrics: &str) {
+        self.set_raw("LYRICS", vec![lyrics.to_string()]);
+    }
+}
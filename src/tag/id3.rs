@@ -1,4 +1,4 @@
-use id3::frame::{Picture, PictureType, Timestamp, UniqueFileIdentifier};
+use id3::frame::{Comment, Lyrics, Picture, PictureType, Timestamp, UniqueFileIdentifier};
 use id3::{Tag, TagLike, Version};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -55,6 +55,22 @@ impl super::Tag for ID3Tag {
             Field::Genre => "TCON",
             Field::Label => "TPUB",
             Field::AlbumArtist => "TPE2",
+            Field::Isrc => "TSRC",
+            Field::Copyright => "TCOP",
+            Field::Composer => "TCOM",
+            Field::Bpm => "TBPM",
+            Field::Comment => {
+                self.tag.add_frame(Comment {
+                    lang: "eng".to_string(),
+                    description: String::new(),
+                    text: value.join(&self.separator),
+                });
+                return;
+            }
+            Field::Lyrics => {
+                self.set_lyrics(&value.join(&self.separator));
+                return;
+            }
         };
         self.set_raw(tag, value);
     }
@@ -83,4 +99,12 @@ impl super::Tag for ID3Tag {
             identifier: track_id.into(),
         });
     }
+
+    fn set_lyrics(&mut self, lyrics: &str) {
+        self.tag.add_frame(Lyrics {
+            lang: "eng".to_string(),
+            description: String::new(),
+            text: lyrics.to_string(),
+        });
+    }
 }
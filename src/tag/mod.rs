@@ -3,15 +3,18 @@ use std::path::Path;
 use crate::downloader::AudioFormat;
 use crate::error::SpotifyError;
 
+use self::flac::FlacTag;
 use self::id3::ID3Tag;
 use ogg::OggTag;
 
+mod flac;
 mod id3;
 mod ogg;
 
 pub enum TagWrap {
     Ogg(OggTag),
     Id3(ID3Tag),
+    Flac(FlacTag),
 }
 
 impl TagWrap {
@@ -20,6 +23,7 @@ impl TagWrap {
         match format {
             AudioFormat::Ogg => Ok(TagWrap::Ogg(OggTag::open(path)?)),
             AudioFormat::Mp3 => Ok(TagWrap::Id3(ID3Tag::open(path)?)),
+            AudioFormat::Flac => Ok(TagWrap::Flac(FlacTag::open(path)?)),
             _ => Err(SpotifyError::Error("Invalid format!".into())),
         }
     }
@@ -29,6 +33,7 @@ impl TagWrap {
         match self {
             TagWrap::Ogg(tag) => tag,
             TagWrap::Id3(tag) => tag,
+            TagWrap::Flac(tag) => tag,
         }
     }
 }
@@ -42,6 +47,8 @@ pub trait Tag {
     fn add_cover(&mut self, mime: &str, data: Vec<u8>);
     /// Adds the file identifier of the track
     fn add_unique_file_identifier(&mut self, track_id: &str);
+    /// Set unsynchronized lyrics
+    fn set_lyrics(&mut self, _lyrics: &str) {}
     fn save(&mut self) -> Result<(), SpotifyError>;
 }
 
@@ -55,4 +62,10 @@ pub enum Field {
     AlbumArtist,
     Genre,
     Label,
+    Isrc,
+    Copyright,
+    Composer,
+    Bpm,
+    Comment,
+    Lyrics,
 }